@@ -15,6 +15,15 @@ use nypc_perf::PerfCalc;
 /// * `j` - Index of the second player (0-based)
 /// * `wij` - Number of wins by player `i` against player `j`
 /// * `wji` - Number of wins by player `j` against player `i`
+/// * `timestamp` - When this battle was played, in the same units as
+///                 `CalcOptions.reference_time`. Reserved for the
+///                 `CalcOptions.half_life` recency weighting described on
+///                 `CalcOptions`; the pinned `nypc_perf` release doesn't
+///                 implement decayed weighting yet, so `calc_perf` rejects
+///                 any call that actually needs it rather than silently
+///                 ignoring it
+/// * `weight` - A pre-computed weight meant to multiply into `wij`/`wji`.
+///              Same caveat as `timestamp`: not yet honored upstream
 ///
 /// # Example
 ///
@@ -34,6 +43,8 @@ pub struct BattleResult {
   pub j: u32,
   pub wij: f64,
   pub wji: f64,
+  pub timestamp: Option<f64>,
+  pub weight: Option<f64>,
 }
 
 impl From<BattleResult> for nypc_perf::BattleResult {
@@ -93,20 +104,63 @@ impl From<Rating> for nypc_perf::Rating {
 ///
 /// * `max_iterations` - Maximum number of iterations before giving up (default: 100)
 /// * `epsilon` - Convergence threshold - algorithm stops when error < epsilon (default: 1e-6)
+/// * `lambda` - L2 (ridge) penalty applied to the log-likelihood, `L - λ·Σπ_i²`,
+///              that would shrink non-anchored ratings toward 0 and keep the
+///              Hessian strictly negative-definite so undefeated/winless
+///              players still converge to a finite rating. The pinned
+///              `nypc_perf` release (`0.1.2`) doesn't implement the
+///              regularized gradient/Hessian yet, so only the no-op default
+///              (`0` or unset) is accepted; any positive value is rejected
+///              rather than silently ignored
+/// * `compute_std_errors` - Whether to derive standard errors from the observed
+///                          Fisher information at convergence. Not yet
+///                          supported by the pinned `nypc_perf` release,
+///                          which doesn't expose the Hessian; only `false`
+///                          or unset is accepted
+/// * `model` - Which pairwise comparison link function to fit. Only
+///             `"bradley_terry"` (logistic, the default) is implemented by
+///             the pinned `nypc_perf` release; `"thurstone"` is rejected
+///             until an upstream release adds the probit link
+/// * `half_life` - Time after which a battle's weight would decay to half, in
+///                 the same units as `BattleResult.timestamp`. Recency
+///                 weighting isn't implemented by the pinned `nypc_perf`
+///                 release, so setting this (together with `reference_time`)
+///                 is rejected rather than silently applying no decay
+/// * `reference_time` - The "now" against which battle timestamps would be
+///                      aged; see `half_life`
+/// * `scale` - Multiplier applied to the raw log-scale ratings to produce
+///             `CalcResult.scaled_ratings` (e.g. `400 / ln(10)` for an Elo-like
+///             scale). The anchor's fixed value defines the zero point
+/// * `offset` - Additive shift applied after `scale` (default: 0)
+/// * `floor` - Minimum value enforced on the scaled ratings, applied after
+///             `scale`/`offset`, mirroring a USCF-style rating floor
 ///
 /// # Example
 ///
 /// ```javascript
 /// const options = new CalcOptions({
 ///   max_iterations: 200,  // Allow more iterations for difficult cases
-///   epsilon: 1e-8        // Require higher precision
+///   epsilon: 1e-8,        // Require higher precision
+///   lambda: 0,            // Only 0 (no penalty) is accepted
+///   model: 'bradley_terry',
+///   scale: 400 / Math.log(10),
+///   offset: 1500,
+///   floor: 100
 /// });
 /// ```
 #[napi(object)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Default)]
 pub struct CalcOptions {
   pub max_iterations: Option<u32>,
   pub epsilon: Option<f64>,
+  pub lambda: Option<f64>,
+  pub compute_std_errors: Option<bool>,
+  pub model: Option<String>,
+  pub half_life: Option<f64>,
+  pub reference_time: Option<f64>,
+  pub scale: Option<f64>,
+  pub offset: Option<f64>,
+  pub floor: Option<f64>,
 }
 
 /// Result of a performance calculation.
@@ -119,13 +173,22 @@ pub struct CalcOptions {
 /// # Fields
 ///
 /// * `ratings` - Updated performance ratings for all players
-/// * `iterations` - Number of iterations if converged, null otherwise  
+/// * `iterations` - Number of iterations if converged, null otherwise
 /// * `error` - Final error value if did not converge, null otherwise
+/// * `std_errors` - Always `null` today; reserved for the per-player standard
+///                  errors described on `CalcOptions.compute_std_errors` once
+///                  the pinned `nypc_perf` release exposes the observed
+///                  Fisher information
+/// * `scaled_ratings` - `ratings` after applying `CalcOptions.scale`,
+///                      `offset` and `floor`, present only when at least one
+///                      of those options is set
 #[napi(object)]
 pub struct CalcResult {
   pub ratings: Vec<f64>,
   pub iterations: Option<u32>,
   pub error: Option<f64>,
+  pub std_errors: Option<Vec<f64>>,
+  pub scaled_ratings: Option<Vec<f64>>,
 }
 
 /// Calculates player performance ratings using the Bradley-Terry model.
@@ -200,9 +263,18 @@ pub fn calc_perf(
     {
       return Err(Error::new(Status::InvalidArg, "Invalid battle result"));
     }
+    if battle.timestamp.is_some_and(|t| !t.is_finite()) {
+      return Err(Error::new(Status::InvalidArg, "Invalid battle timestamp"));
+    }
+    if battle.weight.is_some() {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Battle weighting is not yet supported by the pinned nypc_perf release",
+      ));
+    }
   }
-  let max_iterations = options.and_then(|o| o.max_iterations);
-  let epsilon = options.and_then(|o| o.epsilon);
+  let max_iterations = options.as_ref().and_then(|o| o.max_iterations);
+  let epsilon = options.as_ref().and_then(|o| o.epsilon);
   if max_iterations.is_some_and(|m| m == 0) {
     return Err(Error::new(
       Status::InvalidArg,
@@ -215,6 +287,82 @@ pub fn calc_perf(
       "Epsilon must be greater than 0",
     ));
   }
+  let lambda = options.as_ref().and_then(|o| o.lambda);
+  if lambda.is_some_and(|l| !(l >= 0.0 && l.is_finite())) {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "Lambda must be finite and non-negative",
+    ));
+  }
+  if lambda.is_some_and(|l| l > 0.0) {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "Lambda regularization is not yet supported by the pinned nypc_perf release",
+    ));
+  }
+  match options.as_ref().and_then(|o| o.model.as_deref()) {
+    None | Some("bradley_terry") => {}
+    Some("thurstone") => {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "The thurstone model is not yet supported by the pinned nypc_perf release",
+      ))
+    }
+    Some(_) => {
+      return Err(Error::new(
+        Status::InvalidArg,
+        "Model must be 'bradley_terry' or 'thurstone'",
+      ))
+    }
+  }
+  let half_life = options.as_ref().and_then(|o| o.half_life);
+  let reference_time = options.as_ref().and_then(|o| o.reference_time);
+  if half_life.is_some_and(|h| !(h > 0.0 && h.is_finite())) {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "Half life must be finite and greater than 0",
+    ));
+  }
+  if reference_time.is_some_and(|t| !t.is_finite()) {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "Reference time must be finite",
+    ));
+  }
+  if half_life.is_some() != reference_time.is_some() {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "Half life and reference time must be set together",
+    ));
+  }
+  if half_life.is_some() {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "Time-decayed battle weights are not yet supported by the pinned nypc_perf release",
+    ));
+  }
+  let scale = options.as_ref().and_then(|o| o.scale);
+  let offset = options.as_ref().and_then(|o| o.offset);
+  let floor = options.as_ref().and_then(|o| o.floor);
+  if scale.is_some_and(|s| !s.is_finite()) {
+    return Err(Error::new(Status::InvalidArg, "Scale must be finite"));
+  }
+  if offset.is_some_and(|o| !o.is_finite()) {
+    return Err(Error::new(Status::InvalidArg, "Offset must be finite"));
+  }
+  if floor.is_some_and(|f| !f.is_finite()) {
+    return Err(Error::new(Status::InvalidArg, "Floor must be finite"));
+  }
+  let compute_std_errors = options
+    .as_ref()
+    .and_then(|o| o.compute_std_errors)
+    .unwrap_or(false);
+  if compute_std_errors {
+    return Err(Error::new(
+      Status::InvalidArg,
+      "compute_std_errors is not yet supported by the pinned nypc_perf release",
+    ));
+  }
 
   let mut calc = PerfCalc::new();
   if let Some(max_iterations) = max_iterations {
@@ -226,9 +374,247 @@ pub fn calc_perf(
   let mut ratings = ratings.into_iter().map(|r| r.into()).collect::<Vec<_>>();
   let battles = battles.into_iter().map(|b| b.into()).collect::<Vec<_>>();
   let result = calc.run(&mut ratings, &battles);
+  let std_errors = None;
+  let ratings = ratings.into_iter().map(|r| r.value).collect::<Vec<_>>();
+  let scaled_ratings = (scale.is_some() || offset.is_some() || floor.is_some())
+    .then(|| apply_scale(&ratings, scale, offset, floor));
   Ok(CalcResult {
-    ratings: ratings.into_iter().map(|r| r.value).collect(),
+    ratings,
     iterations: result.ok().map(|i| i as u32),
     error: result.err(),
+    std_errors,
+    scaled_ratings,
   })
 }
+
+/// Applies `CalcOptions.scale`/`offset`/`floor` to raw log-scale ratings.
+///
+/// A NaN rating (e.g. from a diverged solve) is left as NaN rather than
+/// clamped to `floor` — `f64::max` would otherwise silently turn a diverged
+/// rating into a plausible-looking floored value.
+fn apply_scale(ratings: &[f64], scale: Option<f64>, offset: Option<f64>, floor: Option<f64>) -> Vec<f64> {
+  let scale = scale.unwrap_or(1.0);
+  let offset = offset.unwrap_or(0.0);
+  ratings
+    .iter()
+    .map(|&r| {
+      let scaled = r * scale + offset;
+      match floor {
+        Some(floor) if !scaled.is_nan() && scaled < floor => floor,
+        _ => scaled,
+      }
+    })
+    .collect()
+}
+
+/// Predicts the win probability of player `i` against player `j` given their
+/// performance ratings, under the Bradley-Terry model assumed by `calc_perf`.
+///
+/// # Parameters
+///
+/// * `rating_i` - Performance rating of player `i` (log-scale)
+/// * `rating_j` - Performance rating of player `j` (log-scale)
+///
+/// # Returns
+///
+/// `P(i beats j) = 1 / (1 + exp(rating_j - rating_i))`
+///
+/// # Example
+///
+/// ```javascript
+/// import { predict } from 'nypc-perf-wasm';
+///
+/// const p = predict(1.0, 0.0); // probability player 0 beats player 1
+/// ```
+#[napi]
+pub fn predict(rating_i: f64, rating_j: f64) -> f64 {
+  1.0 / (1.0 + (rating_j - rating_i).exp())
+}
+
+/// A pair of ratings for batched win-probability prediction via [`predict_many`].
+#[napi(object)]
+#[derive(Debug, Clone, Copy)]
+pub struct RatingPair {
+  pub rating_i: f64,
+  pub rating_j: f64,
+}
+
+/// Batched form of [`predict`] over pairs of ratings.
+///
+/// # Parameters
+///
+/// * `pairs` - A list of `{ rating_i, rating_j }` pairs.
+///
+/// # Returns
+///
+/// The win probability of `i` over `j` for each pair, in order.
+#[napi]
+pub fn predict_many(pairs: Vec<RatingPair>) -> Vec<f64> {
+  pairs
+    .into_iter()
+    .map(|p| predict(p.rating_i, p.rating_j))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn predict_is_even_odds_for_equal_ratings() {
+    assert_eq!(predict(0.0, 0.0), 0.5);
+    assert_eq!(predict(3.0, 3.0), 0.5);
+  }
+
+  #[test]
+  fn predict_favors_the_higher_rating() {
+    assert!(predict(1.0, 0.0) > 0.5);
+    assert!(predict(0.0, 1.0) < 0.5);
+  }
+
+  #[test]
+  fn predict_is_symmetric() {
+    let (a, b) = (2.3, -0.7);
+    assert!((predict(a, b) + predict(b, a) - 1.0).abs() < 1e-12);
+  }
+
+  #[test]
+  fn predict_many_matches_predict_in_order() {
+    let pairs = vec![
+      RatingPair {
+        rating_i: 1.0,
+        rating_j: 0.0,
+      },
+      RatingPair {
+        rating_i: 0.0,
+        rating_j: 0.0,
+      },
+      RatingPair {
+        rating_i: -1.0,
+        rating_j: 2.0,
+      },
+    ];
+    let expected: Vec<f64> = pairs.iter().map(|p| predict(p.rating_i, p.rating_j)).collect();
+    assert_eq!(predict_many(pairs), expected);
+  }
+
+  #[test]
+  fn apply_scale_scales_and_offsets_values_above_the_floor() {
+    let scaled = apply_scale(&[0.0, -5.0, 5.0], Some(2.0), Some(1500.0), Some(100.0));
+    assert_eq!(scaled, vec![1500.0, 1490.0, 1510.0]);
+  }
+
+  #[test]
+  fn apply_scale_does_not_mask_nan_behind_the_floor() {
+    let scaled = apply_scale(&[f64::NAN, -10.0], Some(1.0), Some(0.0), Some(100.0));
+    assert!(scaled[0].is_nan());
+    assert_eq!(scaled[1], 100.0);
+  }
+
+  fn two_player_battle() -> (Vec<Rating>, Vec<BattleResult>) {
+    (
+      vec![
+        Rating {
+          fixed: false,
+          value: 0.0,
+        },
+        Rating {
+          fixed: true,
+          value: 0.0,
+        },
+      ],
+      vec![BattleResult {
+        i: 0,
+        j: 1,
+        wij: 2.0,
+        wji: 1.0,
+        timestamp: None,
+        weight: None,
+      }],
+    )
+  }
+
+  #[test]
+  fn lambda_zero_is_accepted() {
+    let (ratings, battles) = two_player_battle();
+    let options = CalcOptions {
+      lambda: Some(0.0),
+      ..Default::default()
+    };
+    assert!(calc_perf(ratings, battles, Some(options)).is_ok());
+  }
+
+  #[test]
+  fn lambda_rejects_positive_values() {
+    let (ratings, battles) = two_player_battle();
+    let options = CalcOptions {
+      lambda: Some(0.1),
+      ..Default::default()
+    };
+    assert!(calc_perf(ratings, battles, Some(options)).is_err());
+  }
+
+  #[test]
+  fn model_rejects_unknown_string() {
+    let (ratings, battles) = two_player_battle();
+    let options = CalcOptions {
+      model: Some("elo".to_string()),
+      ..Default::default()
+    };
+    assert!(calc_perf(ratings, battles, Some(options)).is_err());
+  }
+
+  #[test]
+  fn model_thurstone_is_rejected_until_supported() {
+    let (ratings, battles) = two_player_battle();
+    let options = CalcOptions {
+      model: Some("thurstone".to_string()),
+      ..Default::default()
+    };
+    assert!(calc_perf(ratings, battles, Some(options)).is_err());
+  }
+
+  #[test]
+  fn half_life_requires_reference_time() {
+    let (ratings, battles) = two_player_battle();
+    let options = CalcOptions {
+      half_life: Some(30.0),
+      ..Default::default()
+    };
+    assert!(calc_perf(ratings, battles, Some(options)).is_err());
+  }
+
+  #[test]
+  fn reference_time_requires_half_life() {
+    let (ratings, battles) = two_player_battle();
+    let options = CalcOptions {
+      reference_time: Some(100.0),
+      ..Default::default()
+    };
+    assert!(calc_perf(ratings, battles, Some(options)).is_err());
+  }
+
+  #[test]
+  fn battle_weight_is_rejected() {
+    let (ratings, mut battles) = two_player_battle();
+    battles[0].weight = Some(1.0);
+    assert!(calc_perf(ratings, battles, None).is_err());
+  }
+
+  #[test]
+  fn compute_std_errors_is_rejected() {
+    let (ratings, battles) = two_player_battle();
+    let options = CalcOptions {
+      compute_std_errors: Some(true),
+      ..Default::default()
+    };
+    assert!(calc_perf(ratings, battles, Some(options)).is_err());
+  }
+
+  #[test]
+  fn compute_std_errors_unset_leaves_std_errors_null() {
+    let (ratings, battles) = two_player_battle();
+    let result = calc_perf(ratings, battles, None).unwrap();
+    assert!(result.std_errors.is_none());
+  }
+}